@@ -3,12 +3,16 @@ use std::error::Error;
 use std::fmt::Display;
 use std::ops::Not;
 use std::str::FromStr;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, OnceLock, RwLock};
 use anyhow::anyhow;
+use arc_swap::ArcSwap;
 use reqwest::Url;
 use sha2::{Digest, Sha256};
 use sha2::digest::core_api::CoreWrapper;
+use teloxide::Bot;
+use teloxide::prelude::{Message, Requester, ResponseResult};
 use teloxide::types::Me;
+use teloxide::utils::command::BotCommands;
 use crate::domain::{LanguageCode, Ratio, SupportedLanguage};
 use crate::domain::SupportedLanguage::{EN, RU};
 use crate::handlers::perks::HelpPussiesPerk;
@@ -71,11 +75,24 @@ pub enum DickOfDaySelectionMode {
 }
 
 impl AppConfig {
+    /// Never fails: an invalid `DOD_RICH_EXCLUSION_RATIO` just logs a warning and
+    /// disables the feature, same as before config hot-reload existed.
     pub fn from_env() -> Self {
+        Self::build(get_optional_env_ratio("DOD_RICH_EXCLUSION_RATIO"))
+    }
+
+    /// Same as [`Self::from_env`], but reports an invalid `DOD_RICH_EXCLUSION_RATIO` as an
+    /// error instead of silently disabling that setting, so callers that reload the config
+    /// at runtime can keep the previous snapshot in place on failure. Invalid values of the
+    /// other settings still fall back to their defaults, exactly as in `from_env`.
+    pub fn try_from_env() -> anyhow::Result<Self> {
+        Ok(Self::build(get_env_ratio_strict("DOD_RICH_EXCLUSION_RATIO")?))
+    }
+
+    fn build(dod_rich_exclusion_ratio: Option<Ratio>) -> Self {
         let top_limit = get_env_value_or_default("TOP_LIMIT", 10);
         let loan_payout_ratio = get_env_value_or_default("LOAN_PAYOUT_COEF", 0.0);
         let dod_selection_mode = get_optional_env_value("DOD_SELECTION_MODE");
-        let dod_rich_exclusion_ratio = get_optional_env_ratio("DOD_RICH_EXCLUSION_RATIO");
         let chats_merging = get_env_value_or_default("CHATS_MERGING_ENABLED", false);
         let top_unlimited = get_env_value_or_default("TOP_UNLIMITED_ENABLED", false);
         let check_acceptor_length = get_env_value_or_default("PVP_CHECK_ACCEPTOR_LENGTH", false);
@@ -83,8 +100,8 @@ impl AppConfig {
         let show_stats = get_env_value_or_default("PVP_STATS_SHOW", true);
         let show_stats_notice = get_env_value_or_default("PVP_STATS_SHOW_NOTICE", true);
         let announcement_max_shows = get_optional_env_value("ANNOUNCEMENT_MAX_SHOWS");
-        let announcement_en = get_optional_env_value("ANNOUNCEMENT_EN");
-        let announcement_ru = get_optional_env_value("ANNOUNCEMENT_RU");
+        let announcement_en: String = get_optional_env_value("ANNOUNCEMENT_EN");
+        let announcement_ru: String = get_optional_env_value("ANNOUNCEMENT_RU");
         Self {
             features: FeatureToggles {
                 chats_merging,
@@ -101,13 +118,13 @@ impl AppConfig {
             loan_payout_ratio,
             dod_rich_exclusion_ratio,
             announcements: AnnouncementsConfig {
-                max_shows: announcement_max_shows,
+                default_max_shows: announcement_max_shows,
                 announcements: [
-                    (EN, announcement_en),
-                    (RU, announcement_ru),
-                ].map(|(lc, text)| (lc, Announcement::new(text)))
-                 .into_iter()
-                 .filter_map(|(lc, mb_ann)| mb_ann.map(|ann| (lc, ann)))
+                    (EN, "en", announcement_en),
+                    (RU, "ru", announcement_ru),
+                ].into_iter()
+                 .map(|(lc, key, env_text)| (lc, build_announcements(key, env_text, announcement_max_shows)))
+                 .filter(|(_, list)| !list.is_empty())
                  .collect()
             },
             command_toggles: Default::default(),
@@ -115,6 +132,109 @@ impl AppConfig {
     }
 }
 
+/// Shared, hot-reloadable handle to the [`AppConfig`].
+///
+/// Handlers clone this cheaply (it's just an `Arc`) instead of holding a plain
+/// `AppConfig`, so a [`Self::reload`] call is picked up by the next request while any
+/// handler already in flight keeps working off the snapshot it loaded.
+#[derive(Clone)]
+pub struct SharedAppConfig(Arc<ArcSwap<AppConfig>>);
+
+impl SharedAppConfig {
+    pub fn new(initial: AppConfig) -> Self {
+        Self(Arc::new(ArcSwap::from_pointee(initial)))
+    }
+
+    pub fn load(&self) -> Arc<AppConfig> {
+        self.0.load_full()
+    }
+
+    /// Re-reads the config file and environment and atomically swaps in the new config.
+    /// On a validation error both the config file layer and the previously active
+    /// `AppConfig` are left exactly as they were — nothing is applied partially — and
+    /// the error is returned for the caller (a `/reload` handler or the `SIGHUP`
+    /// listener) to report.
+    pub fn reload(&self) -> anyhow::Result<()> {
+        let previous_source = ConfigSource::global();
+        ConfigSource::reload();
+        match AppConfig::try_from_env() {
+            Ok(new_config) => {
+                self.0.store(Arc::new(new_config));
+                log::info!("AppConfig was reloaded");
+                Ok(())
+            }
+            Err(e) => {
+                // Roll the file layer back too, so a half-bad reload can't leave
+                // get_env_mandatory_value/get_env_value_or_default reading the new,
+                // only-partially-valid file while AppConfig still reflects the old one.
+                ConfigSource::swap().store(previous_source);
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Reloads the config whenever the process receives `SIGHUP`, e.g. from `kill -HUP` or
+/// an orchestrator's config-change hook. Errors are logged and otherwise ignored, since
+/// the previous config remains active and in use.
+pub fn spawn_sighup_listener(shared_config: SharedAppConfig) {
+    tokio::spawn(async move {
+        let mut sighup = match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()) {
+            Ok(sighup) => sighup,
+            Err(e) => {
+                log::error!("couldn't subscribe to SIGHUP, config hot-reload via signal is disabled: {e}");
+                return;
+            }
+        };
+        while sighup.recv().await.is_some() {
+            log::info!("received SIGHUP, reloading AppConfig...");
+            if let Err(e) = shared_config.reload() {
+                log::error!("failed to reload AppConfig, keeping the previous one: {e}");
+            }
+        }
+    });
+}
+
+/// Backing implementation for the admin-only `/reload` command: re-reads the env and
+/// reports the outcome as a chat-facing message, leaving the active config untouched on
+/// failure.
+pub fn handle_reload_command(shared_config: &SharedAppConfig) -> String {
+    match shared_config.reload() {
+        Ok(()) => "✅ config reloaded".to_owned(),
+        Err(e) => format!("⚠️ failed to reload config, the previous one is still active: {e}"),
+    }
+}
+
+/// Operator-only maintenance commands. Not attached to a dispatcher by this crate slice
+/// (no `main.rs`/command dispatch module is present here) — wire it up with
+/// `.branch(dptree::entry().filter_command::<AdminCommand>().endpoint(handle_admin_command))`
+/// and add the `SharedAppConfig` created by [`SharedAppConfig::new`] (with
+/// [`spawn_sighup_listener`] started alongside it) to the dispatcher's `dptree::deps![...]`.
+#[derive(BotCommands, Clone)]
+#[command(rename_rule = "snake_case")]
+pub enum AdminCommand {
+    #[command(description = "reload AppConfig from the environment and config file")]
+    Reload,
+}
+
+/// Endpoint for [`AdminCommand::Reload`]: only the operator named by the
+/// `RELOAD_ADMIN_USERNAME` setting may trigger it, everyone else is refused.
+pub async fn handle_admin_command(bot: Bot, msg: Message, cmd: AdminCommand, shared_config: SharedAppConfig) -> ResponseResult<()> {
+    let AdminCommand::Reload = cmd;
+    let reply = if is_reload_operator(msg.from().and_then(|u| u.username.as_deref())) {
+        handle_reload_command(&shared_config)
+    } else {
+        "⛔ only the configured operator can reload the config".to_owned()
+    };
+    bot.send_message(msg.chat.id, reply).await?;
+    Ok(())
+}
+
+fn is_reload_operator(username: Option<&str>) -> bool {
+    let configured: String = get_env_value_or_default("RELOAD_ADMIN_USERNAME", String::new());
+    !configured.is_empty() && username.is_some_and(|u| u.eq_ignore_ascii_case(&configured))
+}
+
 impl DatabaseConfig {
     pub fn from_env() -> anyhow::Result<Self> {
         Ok(Self {
@@ -148,31 +268,92 @@ impl CachedEnvToggles {
     }
 }
 
+/// Rotation granularity for [`AnnouncementsConfig::get`]: all eligible announcements for
+/// a language take turns being "the" active one on this cadence, instead of the first
+/// one in the list always winning.
+const ANNOUNCEMENT_ROTATION_PERIOD_SECS: u64 = 60 * 60;
+
 #[derive(Clone, Default)]
 pub struct AnnouncementsConfig {
-    pub max_shows: usize,
-    pub announcements: HashMap<SupportedLanguage, Announcement>,
+    pub default_max_shows: usize,
+    pub announcements: HashMap<SupportedLanguage, Vec<Announcement>>,
 }
 
 impl AnnouncementsConfig {
+    /// Returns the currently active announcement for `lang_code`, if any: the one(s)
+    /// whose active window covers now, rotated across on [`ANNOUNCEMENT_ROTATION_PERIOD_SECS`]
+    /// so a queue of time-boxed announcements all get shown rather than only the first.
     pub fn get(&self, lang_code: &LanguageCode) -> Option<&Announcement> {
-        self.announcements.get(&lang_code.to_supported_language())
+        self.active_at(lang_code, now_unix())
+    }
+
+    fn active_at(&self, lang_code: &LanguageCode, now: u64) -> Option<&Announcement> {
+        let candidates = self.announcements.get(&lang_code.to_supported_language())?;
+        let eligible: Vec<&Announcement> = candidates.iter().filter(|a| a.is_active_at(now)).collect();
+        let idx = (now / ANNOUNCEMENT_ROTATION_PERIOD_SECS) as usize % eligible.len().max(1);
+        eligible.get(idx).copied()
     }
 }
 
+fn now_unix() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system clock is before the UNIX epoch")
+        .as_secs()
+}
+
 #[derive(Clone)]
 pub struct Announcement {
     pub text: Arc<String>,
     pub hash: Arc<Vec<u8>>,
+    pub max_shows: usize,
+    pub valid_from: Option<u64>,
+    pub valid_until: Option<u64>,
 }
 
 impl Announcement {
-    fn new(text: String) -> Option<Self> {
-        text.is_empty().not().then(|| Self  {
+    fn new(text: String, max_shows: usize, valid_from: Option<u64>, valid_until: Option<u64>) -> Option<Self> {
+        text.is_empty().not().then(|| Self {
             hash: Arc::new(hash(&text)),
             text: Arc::new(text),
+            max_shows,
+            valid_from,
+            valid_until,
         })
     }
+
+    fn is_active_at(&self, now: u64) -> bool {
+        self.valid_from.map_or(true, |from| now >= from) && self.valid_until.map_or(true, |until| now < until)
+    }
+}
+
+/// An announcement entry as it appears under `announcements.entries.<lang>` in the
+/// config file, see [`ConfigSource`]. `valid_from`/`valid_until` are unix timestamps in
+/// seconds.
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnnouncementSpec {
+    text: String,
+    #[serde(default)]
+    max_shows: Option<usize>,
+    #[serde(default)]
+    valid_from: Option<u64>,
+    #[serde(default)]
+    valid_until: Option<u64>,
+}
+
+/// Builds the announcement list for one language: the file-provided entries from
+/// `announcements.entries.<lang_key>` (each with its own optional window and
+/// `max_shows`), plus the legacy single `ANNOUNCEMENT_EN`/`ANNOUNCEMENT_RU`-style env
+/// value as an always-active entry, so the env-only deployment path keeps working
+/// unchanged.
+fn build_announcements(lang_key: &str, env_text: String, default_max_shows: usize) -> Vec<Announcement> {
+    let mut announcements: Vec<Announcement> = ConfigSource::global().lookup_announcements(lang_key)
+        .into_iter()
+        .filter_map(|spec| Announcement::new(spec.text, spec.max_shows.unwrap_or(default_max_shows), spec.valid_from, spec.valid_until))
+        .collect();
+    announcements.extend(Announcement::new(env_text, default_max_shows, None, None));
+    announcements
 }
 
 pub fn build_context_for_help_messages(me: Me, incr: &Incrementor, competitor_bots: &[&str]) -> anyhow::Result<help::Context> {
@@ -203,9 +384,10 @@ where
     T: FromStr<Err = E>,
     E: Error + Send + Sync + 'static
 {
-    std::env::var(key)?
-        .parse()
-        .map_err(|e: E| anyhow!(e))
+    match raw_value_for(key) {
+        Some(v) => v.parse().map_err(|e: E| anyhow!(e)),
+        None => Err(anyhow!("no value was found for the mandatory setting {key} in the environment or the config file")),
+    }
 }
 
 pub(crate) fn get_env_value_or_default<T, E>(key: &str, default: T) -> T
@@ -213,17 +395,174 @@ where
     T: FromStr<Err = E> + Display,
     E: Error + Send + Sync + 'static
 {
-    std::env::var(key)
-        .map_err(|e| {
-            log::warn!("no value was found for an optional environment variable {key}, using the default value {default}");
-            anyhow!(e)
-        })
-        .and_then(|v| v.parse()
-            .map_err(|e: E| {
-                log::warn!("invalid value of the {key} environment variable, using the default value {default}");
-                anyhow!(e)
-            }))
-        .unwrap_or(default)
+    match raw_value_for(key) {
+        Some(v) => v.parse().unwrap_or_else(|e: E| {
+            log::warn!("invalid value of the {key} setting, using the default value {default}: {}", anyhow!(e));
+            default
+        }),
+        None => {
+            log::warn!("no value was found for an optional setting {key}, using the default value {default}");
+            default
+        }
+    }
+}
+
+/// Looks up `key` in the environment first, falling back to the [`ConfigSource`] file
+/// layer when it's unset, so every existing helper gets layered configuration for free.
+fn raw_value_for(key: &str) -> Option<String> {
+    std::env::var(key).ok().or_else(|| ConfigSource::global().lookup(&env_key_to_path(key)))
+}
+
+/// Base configuration layer read at first use, and again on every
+/// [`SharedAppConfig::reload`], from an optional `config.toml`/`config.json` file
+/// (path overridable via `CONFIG_FILE`), overridden by environment variables. Unknown
+/// top-level keys are logged as warnings rather than failing startup, so a typo in the
+/// file surfaces without bricking the bot.
+struct ConfigSource {
+    file: Option<serde_json::Value>,
+}
+
+/// Every top-level key the env-to-path lookup (see [`env_key_to_path`]) can ever
+/// produce, kept in sync with every `get_env_mandatory_value`/`get_env_value_or_default`
+/// call site so a legitimately-configured key never gets flagged as an unknown one.
+const KNOWN_TOP_LEVEL_KEYS: &[&str] = &[
+    "top_limit", "loan_payout_coef", "dod_selection_mode", "dod_rich_exclusion_ratio",
+    "chats_merging_enabled", "top_unlimited_enabled", "pvp", "announcements",
+    "database_url", "database_max_connections",
+    "help_admin_username", "help_admin_channel_ru", "help_admin_channel_en", "help_git_repo",
+    "reload_admin_username",
+];
+
+/// Keys `env_key_to_path` nests under `pvp.*`, kept in sync with the `PVP_*` env vars
+/// read in [`AppConfig::build`].
+const KNOWN_PVP_KEYS: &[&str] = &["check_acceptor_length", "callback_locks_enabled", "stats_show", "stats_show_notice"];
+
+/// Keys `env_key_to_path` nests under `announcements.*`, plus `entries` for the
+/// per-language scheduled-announcement lists read by [`ConfigSource::lookup_announcements`].
+const KNOWN_ANNOUNCEMENTS_KEYS: &[&str] = &["max_shows", "en", "ru", "entries"];
+
+impl ConfigSource {
+    fn swap() -> &'static ArcSwap<ConfigSource> {
+        static INSTANCE: OnceLock<ArcSwap<ConfigSource>> = OnceLock::new();
+        INSTANCE.get_or_init(|| ArcSwap::from_pointee(Self::load()))
+    }
+
+    fn global() -> Arc<Self> {
+        Self::swap().load_full()
+    }
+
+    /// Re-reads `config.toml`/`config.json` from disk and swaps it in for every future
+    /// [`Self::global`] lookup, so file-based settings participate in
+    /// [`SharedAppConfig::reload`] the same way env vars already do.
+    fn reload() {
+        Self::swap().store(Arc::new(Self::load()));
+    }
+
+    fn load() -> Self {
+        let base_path = std::env::var("CONFIG_FILE").unwrap_or_else(|_| "config".to_owned());
+        let file = Self::read(&format!("{base_path}.toml"))
+            .or_else(|| Self::read(&format!("{base_path}.json")));
+        if let Some(file) = &file {
+            Self::warn_about_unknown_keys(file);
+        }
+        Self { file }
+    }
+
+    fn read(path: &str) -> Option<serde_json::Value> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let value = if path.ends_with(".json") {
+            serde_json::from_str(&content)
+                .inspect_err(|e| log::warn!("couldn't parse the config file {path}: {e}"))
+                .ok()?
+        } else {
+            let toml_value: toml::Value = toml::from_str(&content)
+                .inspect_err(|e| log::warn!("couldn't parse the config file {path}: {e}"))
+                .ok()?;
+            serde_json::to_value(toml_value).ok()?
+        };
+        log::info!("loaded the base configuration layer from {path}");
+        Some(value)
+    }
+
+    /// Looks up a dotted path, e.g. `pvp.check_acceptor_length`, produced by
+    /// [`env_key_to_path`], returning it as a string regardless of its underlying
+    /// TOML/JSON scalar type.
+    fn lookup(&self, dotted_key: &str) -> Option<String> {
+        let mut current = self.file.as_ref()?;
+        for part in dotted_key.split('.') {
+            current = current.get(part)?;
+        }
+        match current {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Bool(_) | serde_json::Value::Number(_) => Some(current.to_string()),
+            _ => {
+                log::warn!("config file key '{dotted_key}' has an unsupported shape, ignoring it");
+                None
+            }
+        }
+    }
+
+    /// Reads the `announcements.entries.<lang_key>` array, if present, as a list of
+    /// [`AnnouncementSpec`]s. Nested one level deeper than `announcements.<lang_key>`
+    /// (which [`env_key_to_path`] maps the legacy scalar `ANNOUNCEMENT_EN`/`ANNOUNCEMENT_RU`
+    /// env vars onto) so the two lookups can't alias each other. An entry that doesn't
+    /// deserialize is skipped with a warning rather than discarding the whole list.
+    fn lookup_announcements(&self, lang_key: &str) -> Vec<AnnouncementSpec> {
+        let Some(entries) = self.file.as_ref()
+            .and_then(|f| f.get("announcements"))
+            .and_then(|a| a.get("entries"))
+            .and_then(|a| a.get(lang_key))
+            .and_then(|v| v.as_array())
+        else {
+            return Vec::new();
+        };
+        entries.iter()
+            .filter_map(|entry| serde_json::from_value(entry.clone())
+                .inspect_err(|e| log::warn!("invalid announcement entry for '{lang_key}' in the config file: {e}"))
+                .ok())
+            .collect()
+    }
+
+    fn warn_about_unknown_keys(file: &serde_json::Value) {
+        let Some(map) = file.as_object() else {
+            log::warn!("the config file's root is not a table, ignoring it entirely");
+            return;
+        };
+        for key in map.keys() {
+            if !KNOWN_TOP_LEVEL_KEYS.contains(&key.as_str()) {
+                log::warn!("unknown top-level key '{key}' in the config file, ignoring it");
+            }
+        }
+        Self::warn_about_unknown_nested_keys(file, "pvp", KNOWN_PVP_KEYS);
+        Self::warn_about_unknown_nested_keys(file, "announcements", KNOWN_ANNOUNCEMENTS_KEYS);
+    }
+
+    /// Same as [`Self::warn_about_unknown_keys`], one level down: flags e.g.
+    /// `pvp.chekc_acceptor_length` the same way a typo'd top-level key is flagged.
+    fn warn_about_unknown_nested_keys(file: &serde_json::Value, table: &str, known_keys: &[&str]) {
+        let Some(map) = file.get(table).and_then(|t| t.as_object()) else {
+            return;
+        };
+        for key in map.keys() {
+            if !known_keys.contains(&key.as_str()) {
+                log::warn!("unknown key '{table}.{key}' in the config file, ignoring it");
+            }
+        }
+    }
+}
+
+/// Mechanically derives the dotted [`ConfigSource`] path for an environment variable
+/// name, grouping the well-known `PVP_*` and `ANNOUNCEMENT_*` families under their own
+/// tables so they nest the same way [`FeatureToggles::pvp`] and [`AnnouncementsConfig`]
+/// do, e.g. `PVP_CHECK_ACCEPTOR_LENGTH` -> `pvp.check_acceptor_length`.
+fn env_key_to_path(env_key: &str) -> String {
+    let lower = env_key.to_lowercase();
+    for (prefix, table) in [("pvp_", "pvp"), ("announcement_", "announcements")] {
+        if let Some(rest) = lower.strip_prefix(prefix) {
+            return format!("{table}.{rest}");
+        }
+    }
+    lower
 }
 
 fn get_optional_env_value<T>(key: &str) -> T
@@ -241,6 +580,21 @@ fn get_optional_env_ratio(key: &str) -> Option<Ratio> {
         .ok()
 }
 
+/// Like [`get_optional_env_ratio`], but treats a present-and-invalid value as a hard
+/// error instead of silently disabling the feature. Used by [`AppConfig::try_from_env`]
+/// so that a reload with a typo'd ratio is rejected rather than applied half-broken.
+fn get_env_ratio_strict(key: &str) -> anyhow::Result<Option<Ratio>> {
+    match raw_value_for(key) {
+        None => Ok(None),
+        Some(raw) => {
+            let value: f32 = raw.parse().map_err(|e| anyhow!("invalid value of the {key} setting: {e}"))?;
+            Ratio::new(value)
+                .map(Some)
+                .map_err(|e| anyhow!("invalid value of the {key} setting: {e}"))
+        }
+    }
+}
+
 fn ensure_starts_with_at_sign(s: String) -> String {
     if s.starts_with('@') {
         s
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use sqlx::PgPool;
+use teloxide::Bot;
+use teloxide::prelude::{Message, Requester, ResponseResult};
+use teloxide::types::{ChatId, User};
+use teloxide::utils::command::BotCommands;
+use crate::config::CachedEnvToggles;
+
+const CACHE_POISONED_MSG: &str = "CommandTogglesRepo cache was poisoned";
+
+/// Persisted, per-chat overrides of the `DISABLE_CMD_*` defaults, backed by the
+/// `command_toggles` table and memoized the same way [`CachedEnvToggles`] memoizes the
+/// env-only defaults it falls back to when a chat has no override of its own.
+#[derive(Clone)]
+pub struct CommandTogglesRepo {
+    db: PgPool,
+    cache: Arc<RwLock<HashMap<(i64, String), bool>>>,
+}
+
+impl CommandTogglesRepo {
+    pub fn new(db: PgPool) -> Self {
+        Self { db, cache: Default::default() }
+    }
+
+    /// Resolves whether `key` is enabled in `chat_id`: a per-chat override from the
+    /// `command_toggles` table if one exists, otherwise `env_default`'s process-wide
+    /// `DISABLE_CMD_<KEY>` value.
+    pub async fn enabled(&self, chat_id: ChatId, key: &str, env_default: &CachedEnvToggles) -> anyhow::Result<bool> {
+        let cache_key = (chat_id.0, key.to_owned());
+        if let Some(enabled) = self.cache.read().expect(CACHE_POISONED_MSG).get(&cache_key).copied() {
+            return Ok(enabled);
+        }
+
+        let row: Option<bool> = sqlx::query_scalar("select enabled from command_toggles where chat_id = $1 and command_key = $2")
+            .bind(chat_id.0)
+            .bind(key)
+            .fetch_optional(&self.db)
+            .await?;
+        let enabled = row.unwrap_or_else(|| env_default.enabled(key));
+
+        self.cache.write().expect(CACHE_POISONED_MSG).insert(cache_key, enabled);
+        Ok(enabled)
+    }
+
+    /// Sets (or replaces) the override for `chat_id`, invalidating the cached value so
+    /// the next [`Self::enabled`] call picks it up immediately.
+    pub async fn set_enabled(&self, chat_id: ChatId, key: &str, enabled: bool) -> anyhow::Result<()> {
+        sqlx::query("insert into command_toggles (chat_id, command_key, enabled) values ($1, $2, $3) \
+                     on conflict (chat_id, command_key) do update set enabled = excluded.enabled")
+            .bind(chat_id.0)
+            .bind(key)
+            .bind(enabled)
+            .execute(&self.db)
+            .await?;
+
+        self.cache.write().expect(CACHE_POISONED_MSG).insert((chat_id.0, key.to_owned()), enabled);
+        Ok(())
+    }
+
+    /// Drops the per-chat override, reverting `key` back to the env default in `chat_id`.
+    pub async fn clear_override(&self, chat_id: ChatId, key: &str) -> anyhow::Result<()> {
+        sqlx::query("delete from command_toggles where chat_id = $1 and command_key = $2")
+            .bind(chat_id.0)
+            .bind(key)
+            .execute(&self.db)
+            .await?;
+
+        self.cache.write().expect(CACHE_POISONED_MSG).remove(&(chat_id.0, key.to_owned()));
+        Ok(())
+    }
+}
+
+/// Backing implementation for the chat-admin `/enable_cmd` and `/disable_cmd` commands.
+pub async fn handle_set_command_toggle(repo: &CommandTogglesRepo, chat_id: ChatId, key: &str, enabled: bool) -> String {
+    match repo.set_enabled(chat_id, key, enabled).await {
+        Ok(()) => {
+            let state = if enabled { "enabled" } else { "disabled" };
+            format!("✅ '{key}' is now {state} in this chat")
+        }
+        Err(e) => format!("⚠️ failed to update the toggle for '{key}': {e}"),
+    }
+}
+
+/// Chat-admin commands for [`CommandTogglesRepo`]. Not attached to a dispatcher by this
+/// crate slice (no `main.rs`/command dispatch module is present here) — wire it up with
+/// `.branch(dptree::entry().filter_command::<ChatAdminCommand>().endpoint(handle_chat_admin_command))`
+/// and add the chat's `CommandTogglesRepo` (and the existing `CachedEnvToggles` env
+/// default) to the dispatcher's `dptree::deps![...]`.
+#[derive(BotCommands, Clone)]
+#[command(rename_rule = "snake_case", parse_with = "split")]
+pub enum ChatAdminCommand {
+    #[command(description = "enable a command in this chat")]
+    EnableCmd(String),
+    #[command(description = "disable a command in this chat")]
+    DisableCmd(String),
+}
+
+/// Endpoint for [`ChatAdminCommand`]: only a Telegram admin of the chat the command was
+/// sent in may enable/disable a command for that chat.
+pub async fn handle_chat_admin_command(bot: Bot, msg: Message, cmd: ChatAdminCommand, repo: CommandTogglesRepo) -> ResponseResult<()> {
+    let reply = if !is_chat_admin(&bot, msg.chat.id, msg.from()).await {
+        "⛔ only chat admins can change command toggles".to_owned()
+    } else {
+        let (key, enabled) = match cmd {
+            ChatAdminCommand::EnableCmd(key) => (key, true),
+            ChatAdminCommand::DisableCmd(key) => (key, false),
+        };
+        handle_set_command_toggle(&repo, msg.chat.id, &key, enabled).await
+    };
+    bot.send_message(msg.chat.id, reply).await?;
+    Ok(())
+}
+
+async fn is_chat_admin(bot: &Bot, chat_id: ChatId, user: Option<&User>) -> bool {
+    let Some(user) = user else { return false; };
+    match bot.get_chat_administrators(chat_id).await {
+        Ok(admins) => admins.iter().any(|member| member.user.id == user.id),
+        Err(e) => {
+            log::warn!("couldn't fetch chat administrators for {chat_id}: {e}");
+            false
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use teloxide::prelude::ChatId;
+use testcontainers::clients;
+use crate::config::CachedEnvToggles;
+use crate::repo::command_toggles::CommandTogglesRepo;
+use crate::repo::test::{CHAT_ID, start_postgres};
+
+#[tokio::test]
+async fn test_all() {
+    let docker = clients::Cli::default();
+    let (_container, db) = start_postgres(&docker).await;
+    let repo = CommandTogglesRepo::new(db);
+    let env_default = CachedEnvToggles::default();
+    let chat_id = ChatId(CHAT_ID);
+    let key = "some_command";
+
+    let enabled = repo.enabled(chat_id, key, &env_default).await
+        .expect("couldn't resolve the toggle");
+    assert!(enabled, "should fall back to the env default (enabled) when no override exists");
+
+    repo.set_enabled(chat_id, key, false).await
+        .expect("couldn't set the override");
+    let enabled = repo.enabled(chat_id, key, &env_default).await
+        .expect("couldn't resolve the toggle");
+    assert!(!enabled, "a per-chat override should take precedence over the env default");
+
+    repo.clear_override(chat_id, key).await
+        .expect("couldn't clear the override");
+    let enabled = repo.enabled(chat_id, key, &env_default).await
+        .expect("couldn't resolve the toggle");
+    assert!(enabled, "clearing the override should fall back to the env default again");
+}